@@ -3,17 +3,63 @@
 pub enum Color {
     None,
     Black,
+    White,
     Blue,
     Green,
     Red,
+    Yellow,
+    Gray,
+    Transparent,
     RGB(u8,u8,u8),
+    RGBA(u8,u8,u8,u8),
+}
+
+impl Color {
+    /// The SVG attribute value for this color: `"none"` for `Color::None`, otherwise a compact
+    /// `#RRGGBB` hex string. Any alpha component (`RGBA`, `Transparent`) is not encoded here —
+    /// read it with `opacity()` and emit it as a separate `fill-opacity`/`stroke-opacity`.
+    pub fn to_svg(&self) -> String {
+        match self {
+            Color::None => "none".to_string(),
+            Color::Black => "#000000".to_string(),
+            Color::White => "#ffffff".to_string(),
+            Color::Blue => "#0000ff".to_string(),
+            Color::Green => "#008000".to_string(),
+            Color::Red => "#ff0000".to_string(),
+            Color::Yellow => "#ffff00".to_string(),
+            Color::Gray => "#808080".to_string(),
+            Color::Transparent => "#000000".to_string(),
+            Color::RGB(r,g,b) => format!("#{:02x}{:02x}{:02x}",r,g,b),
+            Color::RGBA(r,g,b,_) => format!("#{:02x}{:02x}{:02x}",r,g,b),
+        }
+    }
+
+    /// This color's opacity in `0.0..=1.0`. `1.0` for every named/`RGB` color, `0.0` for
+    /// `Transparent`, and `alpha / 255` for `RGBA`.
+    pub fn opacity(&self) -> f64 {
+        match self {
+            Color::Transparent => 0.0,
+            Color::RGBA(_,_,_,a) => *a as f64 / 255.0,
+            _ => 1.0,
+        }
+    }
+}
+
+/// Formats a `stroke`/`fill` attribute for `color`, appending a `stroke-opacity`/`fill-opacity`
+/// attribute when `color` isn't fully opaque.
+fn paint_attr(name: &str, color: &Color) -> String {
+    let mut attr = format!("{}=\"{}\"", name, color.to_svg());
+    if color.opacity() != 1.0 {
+        attr.push_str(&format!(" {}-opacity=\"{}\"", name, color.opacity()));
+    }
+    attr
 }
 
 /// Create a path by specifying stroke, stroke-width, fill and rules such as Move To, etc.
 pub struct Path {
     rules: Vec<String>,
     stroke: Color,
-    stroke_width: usize,
+    stroke_width: f64,
     fill: Color,
 }
 
@@ -22,7 +68,7 @@ impl Path {
         Path {
             rules: Vec::new(),
             stroke: Color::None,
-            stroke_width: 0,
+            stroke_width: 0.0,
             fill: Color::None,
         }
     }
@@ -33,7 +79,7 @@ impl Path {
     }
 
     /// Sets `stroke-width=\"YourWidth\"`. If unset it will remain as `stroke-width=\"0\"`.
-    pub fn set_stroke_width(&mut self, width: usize) {
+    pub fn set_stroke_width(&mut self, width: f64) {
         self.stroke_width = width;
     }
 
@@ -43,20 +89,189 @@ impl Path {
     }
 
     /// Adds rule `"M x y"`
-    pub fn move_to(&mut self, pos: [usize;2]) {
+    pub fn move_to(&mut self, pos: [f64;2]) {
         self.rules.push(format!("M {} {} ",pos[0],pos[1]));
     }
 
     /// Adds rule `"l x y"`
-    pub fn line_to(&mut self, pos: [usize;2]) {
+    pub fn line_to(&mut self, pos: [f64;2]) {
         self.rules.push(format!("L {} {} ",pos[0],pos[1]));
     }
 
     /// Adds rule `"c x1 y1, x2 y2, x y"`
-    pub fn bezier(&mut self, points: [usize;6]) {
+    pub fn bezier(&mut self, points: [f64;6]) {
         self.rules.push(format!("C {} {}, {} {}, {} {} ",points[0],points[1],points[2],points[3],points[4],points[5]));
     }
 
+    /// Adds rule `"m dx dy"` (relative move)
+    pub fn move_to_rel(&mut self, pos: [f64;2]) {
+        self.rules.push(format!("m {} {} ",pos[0],pos[1]));
+    }
+
+    /// Adds rule `"l dx dy"` (relative line)
+    pub fn line_to_rel(&mut self, pos: [f64;2]) {
+        self.rules.push(format!("l {} {} ",pos[0],pos[1]));
+    }
+
+    /// Adds rule `"c dx1 dy1, dx2 dy2, dx dy"` (relative cubic bezier)
+    pub fn bezier_rel(&mut self, points: [f64;6]) {
+        self.rules.push(format!("c {} {}, {} {}, {} {} ",points[0],points[1],points[2],points[3],points[4],points[5]));
+    }
+
+    /// Adds rule `"H x"` (horizontal line to)
+    pub fn line_to_horizontal(&mut self, x: f64) {
+        self.rules.push(format!("H {} ",x));
+    }
+
+    /// Adds rule `"h dx"` (relative horizontal line to)
+    pub fn line_to_horizontal_rel(&mut self, x: f64) {
+        self.rules.push(format!("h {} ",x));
+    }
+
+    /// Adds rule `"V y"` (vertical line to)
+    pub fn line_to_vertical(&mut self, y: f64) {
+        self.rules.push(format!("V {} ",y));
+    }
+
+    /// Adds rule `"v dy"` (relative vertical line to)
+    pub fn line_to_vertical_rel(&mut self, y: f64) {
+        self.rules.push(format!("v {} ",y));
+    }
+
+    /// Adds rule `"Q x1 y1, x y"` (quadratic bezier)
+    pub fn quadratic(&mut self, points: [f64;4]) {
+        self.rules.push(format!("Q {} {}, {} {} ",points[0],points[1],points[2],points[3]));
+    }
+
+    /// Adds rule `"q dx1 dy1, dx dy"` (relative quadratic bezier)
+    pub fn quadratic_rel(&mut self, points: [f64;4]) {
+        self.rules.push(format!("q {} {}, {} {} ",points[0],points[1],points[2],points[3]));
+    }
+
+    /// Adds rule `"S x2 y2, x y"` (smooth cubic bezier, reflecting the previous control point)
+    pub fn smooth_bezier(&mut self, points: [f64;4]) {
+        self.rules.push(format!("S {} {}, {} {} ",points[0],points[1],points[2],points[3]));
+    }
+
+    /// Adds rule `"s dx2 dy2, dx dy"` (relative smooth cubic bezier)
+    pub fn smooth_bezier_rel(&mut self, points: [f64;4]) {
+        self.rules.push(format!("s {} {}, {} {} ",points[0],points[1],points[2],points[3]));
+    }
+
+    /// Adds rule `"T x y"` (smooth quadratic bezier, reflecting the previous control point)
+    pub fn smooth_quadratic(&mut self, pos: [f64;2]) {
+        self.rules.push(format!("T {} {} ",pos[0],pos[1]));
+    }
+
+    /// Adds rule `"t dx dy"` (relative smooth quadratic bezier)
+    pub fn smooth_quadratic_rel(&mut self, pos: [f64;2]) {
+        self.rules.push(format!("t {} {} ",pos[0],pos[1]));
+    }
+
+    /// Adds rule `"A rx ry x_axis_rotation large_arc_flag sweep_flag x y"` (elliptical arc)
+    #[allow(clippy::too_many_arguments)] // mirrors the SVG `A` command's parameter list 1:1
+    pub fn arc_to(&mut self, rx: f64, ry: f64, x_axis_rotation: f64, large_arc_flag: bool, sweep_flag: bool, x: f64, y: f64) {
+        self.rules.push(format!("A {} {} {} {} {} {} {} ",rx,ry,x_axis_rotation,large_arc_flag as u8,sweep_flag as u8,x,y));
+    }
+
+    /// Adds rule `"a rx ry x_axis_rotation large_arc_flag sweep_flag dx dy"` (relative elliptical arc)
+    #[allow(clippy::too_many_arguments)] // mirrors the SVG `a` command's parameter list 1:1
+    pub fn arc_to_rel(&mut self, rx: f64, ry: f64, x_axis_rotation: f64, large_arc_flag: bool, sweep_flag: bool, x: f64, y: f64) {
+        self.rules.push(format!("a {} {} {} {} {} {} {} ",rx,ry,x_axis_rotation,large_arc_flag as u8,sweep_flag as u8,x,y));
+    }
+
+    /// Flattens an elliptical arc from `(x1,y1)` to `(x2,y2)` into cubic `C` segments and
+    /// pushes them onto `rules`, following the endpoint-to-center conversion from the SVG spec
+    /// (appendix B.2.4). `x_axis_rotation` is in degrees, matching the `A` command.
+    ///
+    /// `rx == 0` or `ry == 0` degrades to a straight `line_to`, and a zero-length arc
+    /// (`(x1,y1) == (x2,y2)`) is dropped entirely.
+    #[allow(clippy::too_many_arguments)] // mirrors the SVG `A` command's parameter list 1:1
+    pub fn arc_to_beziers(&mut self, x1: f64, y1: f64, rx: f64, ry: f64, x_axis_rotation: f64, large_arc_flag: bool, sweep_flag: bool, x2: f64, y2: f64) {
+        if x1 == x2 && y1 == y2 {
+            return;
+        }
+
+        if rx == 0.0 || ry == 0.0 {
+            self.line_to([x2,y2]);
+            return;
+        }
+
+        let (mut rx,mut ry) = (rx.abs(),ry.abs());
+        let phi = x_axis_rotation.to_radians();
+        let (sin_phi,cos_phi) = phi.sin_cos();
+
+        // Step 1: rotate (x1,y1)/(x2,y2) into the ellipse's (unrotated) frame
+        let dx2 = (x1 - x2) / 2.0;
+        let dy2 = (y1 - y2) / 2.0;
+        let x1p = cos_phi * dx2 + sin_phi * dy2;
+        let y1p = -sin_phi * dx2 + cos_phi * dy2;
+
+        // Step 2: correct out-of-range radii
+        let lambda = (x1p * x1p) / (rx * rx) + (y1p * y1p) / (ry * ry);
+        if lambda > 1.0 {
+            let scale = lambda.sqrt();
+            rx *= scale;
+            ry *= scale;
+        }
+
+        // Step 3: solve for the center in the ellipse frame, then map back
+        let sign = if large_arc_flag == sweep_flag { -1.0 } else { 1.0 };
+        let num = rx * rx * ry * ry - rx * rx * y1p * y1p - ry * ry * x1p * x1p;
+        let den = rx * rx * y1p * y1p + ry * ry * x1p * x1p;
+        let co = sign * (num / den).max(0.0).sqrt();
+        let cxp = co * rx * y1p / ry;
+        let cyp = -co * ry * x1p / rx;
+
+        let cx = cos_phi * cxp - sin_phi * cyp + (x1 + x2) / 2.0;
+        let cy = sin_phi * cxp + cos_phi * cyp + (y1 + y2) / 2.0;
+
+        // Step 4: start angle and angular sweep
+        let theta1 = Path::arc_angle((1.0,0.0), ((x1p - cxp) / rx,(y1p - cyp) / ry));
+        let mut dtheta = Path::arc_angle(((x1p - cxp) / rx,(y1p - cyp) / ry), ((-x1p - cxp) / rx,(-y1p - cyp) / ry));
+
+        if !sweep_flag && dtheta > 0.0 {
+            dtheta -= 2.0 * std::f64::consts::PI;
+        }
+        if sweep_flag && dtheta < 0.0 {
+            dtheta += 2.0 * std::f64::consts::PI;
+        }
+
+        // Step 5: split into segments of at most 90 degrees each
+        let segments = (dtheta.abs() / (std::f64::consts::PI / 2.0)).ceil().max(1.0);
+        let delta = dtheta / segments;
+        let alpha = 4.0 / 3.0 * (delta / 4.0).tan();
+
+        let mut theta = theta1;
+        for _ in 0..segments as usize {
+            let theta_end = theta + delta;
+
+            let (sin1,cos1) = theta.sin_cos();
+            let (sin2,cos2) = theta_end.sin_cos();
+
+            let p1 = (cx + rx * cos1 * cos_phi - ry * sin1 * sin_phi, cy + rx * cos1 * sin_phi + ry * sin1 * cos_phi);
+            let p2 = (cx + rx * cos2 * cos_phi - ry * sin2 * sin_phi, cy + rx * cos2 * sin_phi + ry * sin2 * cos_phi);
+
+            let d1 = (-rx * sin1 * cos_phi - ry * cos1 * sin_phi, -rx * sin1 * sin_phi + ry * cos1 * cos_phi);
+            let d2 = (-rx * sin2 * cos_phi - ry * cos2 * sin_phi, -rx * sin2 * sin_phi + ry * cos2 * cos_phi);
+
+            let q1 = (p1.0 + alpha * d1.0, p1.1 + alpha * d1.1);
+            let q2 = (p2.0 - alpha * d2.0, p2.1 - alpha * d2.1);
+
+            self.bezier([q1.0,q1.1,q2.0,q2.1,p2.0,p2.1]);
+
+            theta = theta_end;
+        }
+    }
+
+    /// Signed angle (in radians) between two vectors, used by `arc_to_beziers`.
+    fn arc_angle(u: (f64,f64), v: (f64,f64)) -> f64 {
+        let sign = if u.0 * v.1 - u.1 * v.0 < 0.0 { -1.0 } else { 1.0 };
+        let dot = u.0 * v.0 + u.1 * v.1;
+        let len = ((u.0 * u.0 + u.1 * u.1) * (v.0 * v.0 + v.1 * v.1)).sqrt();
+        sign * (dot / len).clamp(-1.0,1.0).acos()
+    }
+
     /// Closes the path with `'Z'`
     pub fn close_path(&mut self) {
         self.rules.push("Z ".to_string());
@@ -78,51 +293,11 @@ impl Path {
         }
         
 
-        path.push_str("\" stroke=\"");
-
-        match &self.stroke {
-            Color::None => {
-                path.push_str("none\" ");
-            },
-            Color::Black => {
-                path.push_str("black\" ");
-            },
-            Color::Blue => {
-                path.push_str("blue\" ");
-            },
-            Color::Green => {
-                path.push_str("green\" ");
-            },
-            Color::Red => {
-                path.push_str("red\" ");
-            },
-            Color::RGB(r,g,b) => {
-                path.push_str(&format!("rgb({},{},{})\" ",r,g,b));
-            },
-        }
-
-        path.push_str(&format!("stroke-width=\"{}\" fill=\"", self.stroke_width));
-
-        match &self.fill {
-            Color::None => {
-                path.push_str("none\" />");
-            },
-            Color::Black => {
-                path.push_str("black\" />");
-            },
-            Color::Blue => {
-                path.push_str("blue\" />");
-            },
-            Color::Green => {
-                path.push_str("green\" />");
-            },
-            Color::Red => {
-                path.push_str("red\" />");
-            },
-            Color::RGB(r,g,b) => {
-                path.push_str(&format!("rgb({},{},{})\" />",r,g,b));
-            },
-        }
+        path.push_str("\" ");
+        path.push_str(&paint_attr("stroke", &self.stroke));
+        path.push_str(&format!(" stroke-width=\"{}\" ", self.stroke_width));
+        path.push_str(&paint_attr("fill", &self.fill));
+        path.push_str(" />");
 
         path
     }
@@ -144,11 +319,451 @@ impl Path {
     }
 }
 
+/// The `stroke`/`stroke-width`/`fill` trio shared by every `Shape`. Embedded in each shape
+/// instead of re-declaring the same three fields on every type; each shape still has its own
+/// `set_stroke_color`/`set_stroke_width`/`set_fill_color` that write into it.
+struct Style {
+    stroke: Color,
+    stroke_width: f64,
+    fill: Color,
+}
+
+impl Style {
+    fn new() -> Style {
+        Style { stroke: Color::None, stroke_width: 0.0, fill: Color::None }
+    }
+
+    /// Formats this style as `stroke="..." stroke-width="..." fill="..."`, ready to drop into
+    /// an element's attribute list.
+    fn attrs(&self) -> String {
+        format!("{} stroke-width=\"{}\" {}", paint_attr("stroke", &self.stroke), self.stroke_width, paint_attr("fill", &self.fill))
+    }
+}
+
+/// A rectangle, drawn with `<rect x=".." y=".." width=".." height=".." />`
+pub struct Rect {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    style: Style,
+}
+
+impl Rect {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Rect {
+        Rect { x, y, width, height, style: Style::new() }
+    }
+
+    /// Sets `stroke=\"YourColor\"`. If unset it will remain as `stroke="none"`.
+    pub fn set_stroke_color(&mut self, color: Color) {
+        self.style.stroke = color;
+    }
+
+    /// Sets `stroke-width=\"YourWidth\"`. If unset it will remain as `stroke-width=\"0\"`.
+    pub fn set_stroke_width(&mut self, width: f64) {
+        self.style.stroke_width = width;
+    }
+
+    /// Sets fill=\"YourColor\". If unset it will remain as `fill=\"none\"`.
+    pub fn set_fill_color(&mut self, color: Color) {
+        self.style.fill = color;
+    }
+
+    /// Returns a `String` with a `<rect .. />` tag
+    pub fn create(&mut self) -> String {
+        format!("<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" {} />",
+                self.x, self.y, self.width, self.height, self.style.attrs())
+    }
+}
+
+/// A circle, drawn with `<circle cx=".." cy=".." r=".." />`
+pub struct Circle {
+    cx: f64,
+    cy: f64,
+    r: f64,
+    style: Style,
+}
+
+impl Circle {
+    pub fn new(cx: f64, cy: f64, r: f64) -> Circle {
+        Circle { cx, cy, r, style: Style::new() }
+    }
+
+    /// Sets `stroke=\"YourColor\"`. If unset it will remain as `stroke="none"`.
+    pub fn set_stroke_color(&mut self, color: Color) {
+        self.style.stroke = color;
+    }
+
+    /// Sets `stroke-width=\"YourWidth\"`. If unset it will remain as `stroke-width=\"0\"`.
+    pub fn set_stroke_width(&mut self, width: f64) {
+        self.style.stroke_width = width;
+    }
+
+    /// Sets fill=\"YourColor\". If unset it will remain as `fill=\"none\"`.
+    pub fn set_fill_color(&mut self, color: Color) {
+        self.style.fill = color;
+    }
+
+    /// Returns a `String` with a `<circle .. />` tag
+    pub fn create(&mut self) -> String {
+        format!("<circle cx=\"{}\" cy=\"{}\" r=\"{}\" {} />",
+                self.cx, self.cy, self.r, self.style.attrs())
+    }
+}
+
+/// An ellipse, drawn with `<ellipse cx=".." cy=".." rx=".." ry=".." />`
+pub struct Ellipse {
+    cx: f64,
+    cy: f64,
+    rx: f64,
+    ry: f64,
+    style: Style,
+}
+
+impl Ellipse {
+    pub fn new(cx: f64, cy: f64, rx: f64, ry: f64) -> Ellipse {
+        Ellipse { cx, cy, rx, ry, style: Style::new() }
+    }
+
+    /// Sets `stroke=\"YourColor\"`. If unset it will remain as `stroke="none"`.
+    pub fn set_stroke_color(&mut self, color: Color) {
+        self.style.stroke = color;
+    }
+
+    /// Sets `stroke-width=\"YourWidth\"`. If unset it will remain as `stroke-width=\"0\"`.
+    pub fn set_stroke_width(&mut self, width: f64) {
+        self.style.stroke_width = width;
+    }
+
+    /// Sets fill=\"YourColor\". If unset it will remain as `fill=\"none\"`.
+    pub fn set_fill_color(&mut self, color: Color) {
+        self.style.fill = color;
+    }
+
+    /// Returns a `String` with an `<ellipse .. />` tag
+    pub fn create(&mut self) -> String {
+        format!("<ellipse cx=\"{}\" cy=\"{}\" rx=\"{}\" ry=\"{}\" {} />",
+                self.cx, self.cy, self.rx, self.ry, self.style.attrs())
+    }
+}
+
+/// A line segment, drawn with `<line x1=".." y1=".." x2=".." y2=".." />`
+pub struct Line {
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+    style: Style,
+}
+
+impl Line {
+    pub fn new(x1: f64, y1: f64, x2: f64, y2: f64) -> Line {
+        Line { x1, y1, x2, y2, style: Style::new() }
+    }
+
+    /// Sets `stroke=\"YourColor\"`. If unset it will remain as `stroke="none"`.
+    pub fn set_stroke_color(&mut self, color: Color) {
+        self.style.stroke = color;
+    }
+
+    /// Sets `stroke-width=\"YourWidth\"`. If unset it will remain as `stroke-width=\"0\"`.
+    pub fn set_stroke_width(&mut self, width: f64) {
+        self.style.stroke_width = width;
+    }
+
+    /// Sets fill=\"YourColor\". If unset it will remain as `fill=\"none\"`.
+    pub fn set_fill_color(&mut self, color: Color) {
+        self.style.fill = color;
+    }
+
+    /// Returns a `String` with a `<line .. />` tag
+    pub fn create(&mut self) -> String {
+        format!("<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" {} />",
+                self.x1, self.y1, self.x2, self.y2, self.style.attrs())
+    }
+}
+
+/// Formats a list of points as the `"x1,y1 x2,y2 .."` syntax shared by `<polyline>`/`<polygon>`
+fn points_attr(points: &[[f64;2]]) -> String {
+    points.iter().map(|p| format!("{},{}", p[0], p[1])).collect::<Vec<String>>().join(" ")
+}
+
+/// Escapes `&`, `<`, `>`, and `"` so arbitrary text can be safely placed inside an SVG element
+fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Formats the background `<rect .. />` for a `MinSVG` of the given size, or an empty
+/// `String` when `color` is `Color::None`.
+fn background_rect(width: f64, height: f64, color: &Color) -> String {
+    if *color == Color::None {
+        return String::new();
+    }
+
+    let mut rect = format!("<rect width=\"{}\" height=\"{}\" style=\"fill:{}", width, height, color.to_svg());
+    if color.opacity() != 1.0 {
+        rect.push_str(&format!(";fill-opacity:{}", color.opacity()));
+    }
+    rect.push_str("\" />");
+    rect
+}
+
+/// An open multi-point line, drawn with `<polyline points=".." />`
+pub struct Polyline {
+    points: Vec<[f64;2]>,
+    style: Style,
+}
+
+impl Polyline {
+    pub fn new(points: Vec<[f64;2]>) -> Polyline {
+        Polyline { points, style: Style::new() }
+    }
+
+    /// Sets `stroke=\"YourColor\"`. If unset it will remain as `stroke="none"`.
+    pub fn set_stroke_color(&mut self, color: Color) {
+        self.style.stroke = color;
+    }
+
+    /// Sets `stroke-width=\"YourWidth\"`. If unset it will remain as `stroke-width=\"0\"`.
+    pub fn set_stroke_width(&mut self, width: f64) {
+        self.style.stroke_width = width;
+    }
+
+    /// Sets fill=\"YourColor\". If unset it will remain as `fill=\"none\"`.
+    pub fn set_fill_color(&mut self, color: Color) {
+        self.style.fill = color;
+    }
+
+    /// Returns a `String` with a `<polyline .. />` tag
+    pub fn create(&mut self) -> String {
+        format!("<polyline points=\"{}\" {} />", points_attr(&self.points), self.style.attrs())
+    }
+}
+
+/// A closed multi-point shape, drawn with `<polygon points=".." />`
+pub struct Polygon {
+    points: Vec<[f64;2]>,
+    style: Style,
+}
+
+impl Polygon {
+    pub fn new(points: Vec<[f64;2]>) -> Polygon {
+        Polygon { points, style: Style::new() }
+    }
+
+    /// Sets `stroke=\"YourColor\"`. If unset it will remain as `stroke="none"`.
+    pub fn set_stroke_color(&mut self, color: Color) {
+        self.style.stroke = color;
+    }
+
+    /// Sets `stroke-width=\"YourWidth\"`. If unset it will remain as `stroke-width=\"0\"`.
+    pub fn set_stroke_width(&mut self, width: f64) {
+        self.style.stroke_width = width;
+    }
+
+    /// Sets fill=\"YourColor\". If unset it will remain as `fill=\"none\"`.
+    pub fn set_fill_color(&mut self, color: Color) {
+        self.style.fill = color;
+    }
+
+    /// Returns a `String` with a `<polygon .. />` tag
+    pub fn create(&mut self) -> String {
+        format!("<polygon points=\"{}\" {} />", points_attr(&self.points), self.style.attrs())
+    }
+}
+
+/// A line of text, drawn with `<text x=".." y="..">content</text>`
+pub struct Text {
+    x: f64,
+    y: f64,
+    content: String,
+    style: Style,
+}
+
+impl Text {
+    pub fn new(x: f64, y: f64, content: String) -> Text {
+        Text { x, y, content, style: Style::new() }
+    }
+
+    /// Sets `stroke=\"YourColor\"`. If unset it will remain as `stroke="none"`.
+    pub fn set_stroke_color(&mut self, color: Color) {
+        self.style.stroke = color;
+    }
+
+    /// Sets `stroke-width=\"YourWidth\"`. If unset it will remain as `stroke-width=\"0\"`.
+    pub fn set_stroke_width(&mut self, width: f64) {
+        self.style.stroke_width = width;
+    }
+
+    /// Sets fill=\"YourColor\". If unset it will remain as `fill=\"none\"`.
+    pub fn set_fill_color(&mut self, color: Color) {
+        self.style.fill = color;
+    }
+
+    /// Returns a `String` with a `<text ..>content</text>` tag
+    pub fn create(&mut self) -> String {
+        format!("<text x=\"{}\" y=\"{}\" {}>{}</text>",
+                self.x, self.y, self.style.attrs(), escape_text(&self.content))
+    }
+}
+
+/// Any non-`Path` drawing primitive that can be added to a `MinSVG` with `add_shape`
+pub enum Shape {
+    Rect(Rect),
+    Circle(Circle),
+    Ellipse(Ellipse),
+    Line(Line),
+    Polyline(Polyline),
+    Polygon(Polygon),
+    Text(Text),
+}
+
+impl Shape {
+    fn create(&mut self) -> String {
+        match self {
+            Shape::Rect(shape) => shape.create(),
+            Shape::Circle(shape) => shape.create(),
+            Shape::Ellipse(shape) => shape.create(),
+            Shape::Line(shape) => shape.create(),
+            Shape::Polyline(shape) => shape.create(),
+            Shape::Polygon(shape) => shape.create(),
+            Shape::Text(shape) => shape.create(),
+        }
+    }
+}
+
+/// Splits a path `d` attribute into individual rule strings, one per command letter, so they
+/// can be fed into `Path::add_rule_raw`. An `e`/`E` immediately preceded by a digit and followed
+/// by a digit or sign is treated as part of a number in scientific notation (e.g. `"1e-5"`)
+/// rather than a command letter.
+fn tokenize_path_d(d: &str) -> Vec<String> {
+    let chars: Vec<char> = d.chars().collect();
+    let mut rules = Vec::new();
+    let mut current = String::new();
+
+    for (i,&ch) in chars.iter().enumerate() {
+        let is_exponent = (ch == 'e' || ch == 'E')
+            && current.chars().last().is_some_and(|c| c.is_ascii_digit())
+            && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit() || *c == '+' || *c == '-');
+
+        if ch.is_ascii_alphabetic() && !is_exponent {
+            if !current.trim().is_empty() {
+                rules.push(format!("{} ", current.split_whitespace().collect::<Vec<&str>>().join(" ")));
+            }
+            current = ch.to_string();
+        } else if ch == ',' {
+            current.push(' ');
+        } else {
+            current.push(ch);
+        }
+    }
+
+    if !current.trim().is_empty() {
+        rules.push(format!("{} ", current.split_whitespace().collect::<Vec<&str>>().join(" ")));
+    }
+
+    rules
+}
+
+/// Parses a `stroke`/`fill` attribute value into a `Color`. Recognizes the named colors,
+/// `rgb(r,g,b)`, and `#rrggbb`/`#rgb` hex; anything else (including `"none"`) becomes `Color::None`.
+fn parse_color(value: &str) -> Color {
+    let value = value.trim();
+
+    match value {
+        "none" => return Color::None,
+        "black" => return Color::Black,
+        "white" => return Color::White,
+        "blue" => return Color::Blue,
+        "green" => return Color::Green,
+        "red" => return Color::Red,
+        "yellow" => return Color::Yellow,
+        "gray" | "grey" => return Color::Gray,
+        "transparent" => return Color::Transparent,
+        _ => {},
+    }
+
+    if let Some(rgb) = value.strip_prefix("rgb(").and_then(|s| s.strip_suffix(')')) {
+        let parts: Vec<&str> = rgb.split(',').map(|n| n.trim()).collect();
+        if parts.len() == 3 {
+            if let (Ok(r),Ok(g),Ok(b)) = (parts[0].parse::<u8>(),parts[1].parse::<u8>(),parts[2].parse::<u8>()) {
+                return Color::RGB(r,g,b);
+            }
+        }
+    }
+
+    if let Some(hex) = value.strip_prefix('#') {
+        let expand = |c: char| u8::from_str_radix(&c.to_string().repeat(2), 16).ok();
+
+        let parsed = match hex.len() {
+            6 => (
+                u8::from_str_radix(&hex[0..2],16).ok(),
+                u8::from_str_radix(&hex[2..4],16).ok(),
+                u8::from_str_radix(&hex[4..6],16).ok(),
+            ),
+            3 => {
+                let mut chars = hex.chars();
+                (
+                    chars.next().and_then(expand),
+                    chars.next().and_then(expand),
+                    chars.next().and_then(expand),
+                )
+            },
+            _ => (None,None,None),
+        };
+
+        if let (Some(r),Some(g),Some(b)) = parsed {
+            return Color::RGB(r,g,b);
+        }
+    }
+
+    Color::None
+}
+
+/// The opaque `(r,g,b)` behind any `Color`, ignoring alpha. Used to fold a separately-parsed
+/// `fill-opacity`/`stroke-opacity` attribute back into a color via `Color::RGBA`.
+fn color_rgb(color: &Color) -> (u8,u8,u8) {
+    match color {
+        Color::None | Color::Black | Color::Transparent => (0,0,0),
+        Color::White => (255,255,255),
+        Color::Blue => (0,0,255),
+        Color::Green => (0,128,0),
+        Color::Red => (255,0,0),
+        Color::Yellow => (255,255,0),
+        Color::Gray => (128,128,128),
+        Color::RGB(r,g,b) | Color::RGBA(r,g,b,_) => (*r,*g,*b),
+    }
+}
+
+/// Combines a `Color` parsed from a `fill`/`stroke` attribute with its `fill-opacity`/
+/// `stroke-opacity` attribute (if any), producing a `Color::RGBA` when the opacity isn't `1.0`.
+fn parse_opacity(color: Color, opacity_attr: Option<&str>) -> Color {
+    if color == Color::None {
+        return color;
+    }
+
+    let opacity = match opacity_attr.and_then(|s| s.parse::<f64>().ok()) {
+        Some(opacity) => opacity,
+        None => return color,
+    };
+
+    if opacity >= 1.0 {
+        return color;
+    }
+
+    let (r,g,b) = color_rgb(&color);
+    Color::RGBA(r,g,b,(opacity.clamp(0.0,1.0) * 255.0).round() as u8)
+}
+
 /// Create an svg structure to hold one or more paths with options to set the viewbox, xmlns, and background color
 pub struct MinSVG {
-    viewbox: [usize;4],
+    viewbox: [f64;4],
     xmlns: Option<String>,
     paths: Vec<Path>,
+    shapes: Vec<Shape>,
     background: Color,
 }
 
@@ -156,11 +771,12 @@ impl MinSVG {
     /// Construct a new svg with the required viewBox
     /// 
     /// Example: `[0,0,100,100]` will result in `viewBox=\"0 0 100 100\"`
-    pub fn new(viewbox: [usize;4]) -> MinSVG {
+    pub fn new(viewbox: [f64;4]) -> MinSVG {
         MinSVG {
             viewbox,
             xmlns: None,
             paths: Vec::new(),
+            shapes: Vec::new(),
             background: Color::None,
         }
     }
@@ -180,6 +796,73 @@ impl MinSVG {
         self.paths.push(path);
     }
 
+    /// Add a shape primitive (`Rect`, `Circle`, `Ellipse`, `Line`, `Polyline`, `Polygon`, or `Text`) to the svg.
+    pub fn add_shape(&mut self, shape: Shape) {
+        self.shapes.push(shape);
+    }
+
+    /// Parses an existing SVG document into a `MinSVG`, the inverse of `create()`.
+    ///
+    /// Only the `viewBox`/`xmlns` attributes of the root `<svg>` and the `d`/`stroke`/
+    /// `stroke-width`/`fill`/`stroke-opacity`/`fill-opacity` attributes of its `<path>` children
+    /// are understood; other elements and attributes (including the background `<rect>`) are
+    /// ignored.
+    pub fn parse(xml: &str) -> Result<MinSVG, String> {
+        use roxmltree::Document;
+
+        let doc = Document::parse(xml).map_err(|e| e.to_string())?;
+        let root = doc.root_element();
+
+        if root.tag_name().name() != "svg" {
+            return Err("root element is not <svg>".to_string());
+        }
+
+        let viewbox_attr = root.attribute("viewBox").ok_or("svg is missing a viewBox attribute")?;
+        let viewbox_parts = viewbox_attr.split_whitespace()
+            .map(|n| n.parse::<f64>().map_err(|e| e.to_string()))
+            .collect::<Result<Vec<f64>,String>>()?;
+
+        if viewbox_parts.len() != 4 {
+            return Err("viewBox must have exactly 4 components".to_string());
+        }
+
+        let mut svg = MinSVG::new([viewbox_parts[0],viewbox_parts[1],viewbox_parts[2],viewbox_parts[3]]);
+
+        if let Some(xmlns) = root.tag_name().namespace() {
+            if xmlns != "http://www.w3.org/2000/svg" {
+                svg.set_xmlns(xmlns.to_string());
+            }
+        }
+
+        for node in root.children().filter(|n| n.is_element() && n.tag_name().name() == "path") {
+            let mut path = Path::new();
+
+            if let Some(d) = node.attribute("d") {
+                for rule in tokenize_path_d(d) {
+                    path.add_rule_raw(rule);
+                }
+            }
+
+            if let Some(stroke) = node.attribute("stroke") {
+                path.set_stroke_color(parse_opacity(parse_color(stroke), node.attribute("stroke-opacity")));
+            }
+
+            if let Some(stroke_width) = node.attribute("stroke-width") {
+                if let Ok(stroke_width) = stroke_width.parse::<f64>() {
+                    path.set_stroke_width(stroke_width);
+                }
+            }
+
+            if let Some(fill) = node.attribute("fill") {
+                path.set_fill_color(parse_opacity(parse_color(fill), node.attribute("fill-opacity")));
+            }
+
+            svg.add_path(path);
+        }
+
+        Ok(svg)
+    }
+
     /// Will return a complete svg with all the requirements.
     pub fn create(&mut self) -> String {
         let mut svg = String::new();
@@ -195,29 +878,14 @@ impl MinSVG {
             }
         }
 
-        match &self.background {
-            Color::None => {
+        svg.push_str(&background_rect(self.viewbox[2], self.viewbox[3], &self.background));
 
-            },
-            Color::Black => {
-                svg.push_str(&format!("<rect width=\"{}\" height=\"{}\" style=\"fill:{}\" />", self.viewbox[2], self.viewbox[3],"black"));
-            },
-            Color::Blue => {
-                svg.push_str(&format!("<rect width=\"{}\" height=\"{}\" style=\"fill:{}\" />", self.viewbox[2], self.viewbox[3],"blue"));
-            },
-            Color::Green => {
-                svg.push_str(&format!("<rect width=\"{}\" height=\"{}\" style=\"fill:{}\" />", self.viewbox[2], self.viewbox[3],"green"));
-            },
-            Color::Red => {
-                svg.push_str(&format!("<rect width=\"{}\" height=\"{}\" style=\"fill:{}\" />", self.viewbox[2], self.viewbox[3],"red"));
-            },
-            Color::RGB(r,g,b) => {
-                svg.push_str(&format!("<rect width=\"{}\" height=\"{}\" style=\"fill:rgb({},{},{})\" />", self.viewbox[2], self.viewbox[3],r,g,b));
-            },
+        for path in &mut self.paths {
+            svg.push_str(&path.create());
         }
 
-        for path in &mut self.paths {
-            svg.push_str(&*path.create());
+        for shape in &mut self.shapes {
+            svg.push_str(&shape.create());
         }
 
         svg.push_str("</svg>");
@@ -229,29 +897,14 @@ impl MinSVG {
     pub fn create_raw(&mut self) -> String {
         let mut svg = String::new();
 
-        match &self.background {
-            Color::None => {
+        svg.push_str(&background_rect(self.viewbox[2], self.viewbox[3], &self.background));
 
-            },
-            Color::Black => {
-                svg.push_str(&format!("<rect width=\"{}\" height=\"{}\" style=\"fill:{}\" />", self.viewbox[2], self.viewbox[3],"black"));
-            },
-            Color::Blue => {
-                svg.push_str(&format!("<rect width=\"{}\" height=\"{}\" style=\"fill:{}\" />", self.viewbox[2], self.viewbox[3],"blue"));
-            },
-            Color::Green => {
-                svg.push_str(&format!("<rect width=\"{}\" height=\"{}\" style=\"fill:{}\" />", self.viewbox[2], self.viewbox[3],"green"));
-            },
-            Color::Red => {
-                svg.push_str(&format!("<rect width=\"{}\" height=\"{}\" style=\"fill:{}\" />", self.viewbox[2], self.viewbox[3],"red"));
-            },
-            Color::RGB(r,g,b) => {
-                svg.push_str(&format!("<rect width=\"{}\" height=\"{}\" style=\"fill:rgb({},{},{})\" />", self.viewbox[2], self.viewbox[3],r,g,b));
-            },
+        for path in &mut self.paths {
+            svg.push_str(&path.create());
         }
 
-        for path in &mut self.paths {
-            svg.push_str(&*path.create());
+        for shape in &mut self.shapes {
+            svg.push_str(&shape.create());
         }
 
         svg.push_str("</svg>");
@@ -259,6 +912,55 @@ impl MinSVG {
         svg
     }
 
+    /// Writes the complete svg (as produced by `create`) to `w`, without building the whole
+    /// document as one `String` first.
+    pub fn write_to<W: std::io::Write>(&mut self, w: &mut W) -> std::io::Result<()> {
+        match &self.xmlns {
+            None => {
+                write!(w, "<svg viewBox=\"{} {} {} {}\" xmlns=\"http://www.w3.org/2000/svg\">",
+                       self.viewbox[0],self.viewbox[1],self.viewbox[2],self.viewbox[3])?;
+            },
+            Some(xmlns) => {
+                write!(w, "<svg viewBox=\"{} {} {} {}\" xmlns=\"{}\">",
+                       self.viewbox[0],self.viewbox[1],self.viewbox[2],self.viewbox[3],xmlns)?;
+            }
+        }
+
+        w.write_all(background_rect(self.viewbox[2], self.viewbox[3], &self.background).as_bytes())?;
+
+        for path in &mut self.paths {
+            w.write_all(path.create().as_bytes())?;
+        }
+
+        for shape in &mut self.shapes {
+            w.write_all(shape.create().as_bytes())?;
+        }
+
+        write!(w, "</svg>")
+    }
+
+    /// Writes the svg to the file at `path`, creating it (or truncating an existing one).
+    pub fn to_file<P: AsRef<std::path::Path>>(&mut self, path: P) -> std::io::Result<()> {
+        use std::fs::File;
+
+        let mut file = File::create(path)?;
+        self.write_to(&mut file)
+    }
+
+    /// Renders the svg into a `Vec<u8>` instead of a `String`.
+    pub fn to_bytes(&mut self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.write_to(&mut bytes).expect("writing to a Vec<u8> never fails");
+        bytes
+    }
+
+    /// Writes the svg to stdout.
+    pub fn to_stdout(&mut self) -> std::io::Result<()> {
+        use std::io::stdout;
+
+        self.write_to(&mut stdout())
+    }
+
 }
 
 
@@ -271,49 +973,133 @@ mod tests {
         let path = Path::new();
         assert_eq!(0,path.rules.len());
         assert_eq!(Color::None,path.stroke);
-        assert_eq!(0,path.stroke_width);
+        assert_eq!(0.0,path.stroke_width);
         assert_eq!(Color::None,path.fill);
     }
 
     #[test]
     fn test_add_path_rule() {
         let mut path = Path::new();
-        path.move_to([0,0]);
-        path.line_to([100,100]);
+        path.move_to([0.0,0.0]);
+        path.line_to([100.0,100.0]);
         assert_eq!("<path d=\"M 0 0 L 100 100 \" stroke=\"none\" stroke-width=\"0\" fill=\"none\" />".to_string(),path.create());
     }
 
     #[test]
     fn test_create_raw_path() {
         let mut path = Path::new();
-        path.move_to([0,0]);
-        path.line_to([100,100]);
+        path.move_to([0.0,0.0]);
+        path.line_to([100.0,100.0]);
+        assert_eq!("M 0 0 L 100 100 ".to_string(),path.create_raw());
+    }
+
+    #[test]
+    fn test_arc_to_beziers_quarter_circle() {
+        let mut path = Path::new();
+        path.move_to([100.0,0.0]);
+        path.arc_to_beziers(100.0,0.0,100.0,100.0,0.0,false,true,0.0,100.0);
+
+        let raw = path.create_raw();
+        assert_eq!(1, raw.matches('C').count());
+
+        let end: Vec<f64> = raw.trim_end().split_whitespace().rev().take(2).map(|n| n.parse().unwrap()).collect();
+        assert!((end[1] - 0.0).abs() < 1e-9);
+        assert!((end[0] - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_arc_to_beziers_zero_radius_is_a_line() {
+        let mut path = Path::new();
+        path.move_to([0.0,0.0]);
+        path.arc_to_beziers(0.0,0.0,0.0,50.0,0.0,false,true,100.0,100.0);
         assert_eq!("M 0 0 L 100 100 ".to_string(),path.create_raw());
     }
 
+    #[test]
+    fn test_arc_to_beziers_zero_length_is_dropped() {
+        let mut path = Path::new();
+        path.move_to([10.0,10.0]);
+        path.arc_to_beziers(10.0,10.0,5.0,5.0,0.0,false,true,10.0,10.0);
+        assert_eq!("M 10 10 ".to_string(),path.create_raw());
+    }
+
+    #[test]
+    fn test_arc_to_beziers_negative_radius_matches_positive() {
+        let mut negative = Path::new();
+        negative.move_to([100.0,0.0]);
+        negative.arc_to_beziers(100.0,0.0,-100.0,100.0,0.0,false,true,0.0,100.0);
+
+        let mut positive = Path::new();
+        positive.move_to([100.0,0.0]);
+        positive.arc_to_beziers(100.0,0.0,100.0,100.0,0.0,false,true,0.0,100.0);
+
+        assert_eq!(positive.create_raw(),negative.create_raw());
+    }
+
+    #[test]
+    fn test_arc_to_beziers_allows_negative_control_points() {
+        let mut path = Path::new();
+        path.move_to([0.0,0.0]);
+        path.arc_to_beziers(0.0,0.0,50.0,50.0,0.0,false,true,100.0,0.0);
+
+        let raw = path.create_raw();
+        let control_points: Vec<f64> = raw.split([' ', ','])
+            .filter(|s| !s.is_empty() && *s != "M" && *s != "C")
+            .map(|n| n.parse().unwrap())
+            .collect();
+
+        assert!(control_points.iter().any(|&n| n < 0.0));
+    }
+
+    #[test]
+    fn test_path_command_grammar() {
+        let mut path = Path::new();
+        path.move_to([0.0,0.0]);
+        path.move_to_rel([10.0,10.0]);
+        path.line_to_rel([5.0,5.0]);
+        path.bezier_rel([1.0,2.0,3.0,4.0,5.0,6.0]);
+        path.line_to_horizontal(50.0);
+        path.line_to_horizontal_rel(5.0);
+        path.line_to_vertical(50.0);
+        path.line_to_vertical_rel(5.0);
+        path.quadratic([1.0,2.0,3.0,4.0]);
+        path.quadratic_rel([1.0,2.0,3.0,4.0]);
+        path.smooth_bezier([1.0,2.0,3.0,4.0]);
+        path.smooth_bezier_rel([1.0,2.0,3.0,4.0]);
+        path.smooth_quadratic([1.0,2.0]);
+        path.smooth_quadratic_rel([1.0,2.0]);
+        path.arc_to(25.0,25.0,0.0,false,true,50.0,50.0);
+        path.arc_to_rel(25.0,25.0,0.0,true,false,50.0,50.0);
+
+        assert_eq!(
+            "M 0 0 m 10 10 l 5 5 c 1 2, 3 4, 5 6 H 50 h 5 V 50 v 5 Q 1 2, 3 4 q 1 2, 3 4 S 1 2, 3 4 s 1 2, 3 4 T 1 2 t 1 2 A 25 25 0 0 1 50 50 a 25 25 0 1 0 50 50 ".to_string(),
+            path.create_raw()
+        );
+    }
+
     #[test]
     fn test_construct_svg() {
-        let svg = MinSVG::new([0,0,100,100]);
-        assert_eq!([0,0,100,100], svg.viewbox);
+        let svg = MinSVG::new([0.0,0.0,100.0,100.0]);
+        assert_eq!([0.0,0.0,100.0,100.0], svg.viewbox);
         assert_eq!(None, svg.xmlns);
     }
 
     #[test]
     fn test_add_xmlns() {
-        let mut svg = MinSVG::new([0,0,100,100]);
+        let mut svg = MinSVG::new([0.0,0.0,100.0,100.0]);
         svg.set_xmlns("Some namespace".to_string());
         assert_eq!(Some("Some namespace".to_string()),svg.xmlns);
     }
 
     #[test]
     fn test_without_xmlns() {
-        let svg = MinSVG::new([0,0,100,100]);
+        let svg = MinSVG::new([0.0,0.0,100.0,100.0]);
         assert_eq!(None,svg.xmlns);
     }
 
     #[test]
     fn test_svg_and_path_rgb() {
-        let mut svg = MinSVG::new([0,0,100,100]);
+        let mut svg = MinSVG::new([0.0,0.0,100.0,100.0]);
         svg.set_background_color(Color::RGB(0,0,0));
 
         let mut path = Path::new();
@@ -321,7 +1107,7 @@ mod tests {
 
         svg.add_path(path);
 
-        assert_eq!("<rect width=\"100\" height=\"100\" style=\"fill:rgb(0,0,0)\" /><path d=\"\" stroke=\"rgb(10,100,50)\" stroke-width=\"0\" fill=\"none\" /></svg>".to_string(),svg.create_raw());
+        assert_eq!("<rect width=\"100\" height=\"100\" style=\"fill:#000000\" /><path d=\"\" stroke=\"#0a6432\" stroke-width=\"0\" fill=\"none\" /></svg>".to_string(),svg.create_raw());
     }
 
     #[test]
@@ -329,23 +1115,23 @@ mod tests {
         use std::fs::File;
         use std::io::prelude::*;
 
-        let mut svg = MinSVG::new([0,0,500,500]);
+        let mut svg = MinSVG::new([0.0,0.0,500.0,500.0]);
 
         let mut path = Path::new();
         path.set_stroke_color(Color::Black);
         path.set_fill_color(Color::Black);
-        path.set_stroke_width(3);
-        path.move_to([0,0]);
-        path.line_to([0,50]);
-        path.line_to([450,500]);
-        path.line_to([500,500]);
-        path.line_to([0,0]);
+        path.set_stroke_width(3.0);
+        path.move_to([0.0,0.0]);
+        path.line_to([0.0,50.0]);
+        path.line_to([450.0,500.0]);
+        path.line_to([500.0,500.0]);
+        path.line_to([0.0,0.0]);
 
         svg.add_path(path);
 
         svg.set_background_color(Color::Green);
 
-        assert_eq!("<svg viewBox=\"0 0 500 500\" xmlns=\"http://www.w3.org/2000/svg\"><rect width=\"500\" height=\"500\" style=\"fill:green\" /><path d=\"M 0 0 L 0 50 L 450 500 L 500 500 L 0 0 \" stroke=\"black\" stroke-width=\"3\" fill=\"black\" /></svg>".to_string(),svg.create());
+        assert_eq!("<svg viewBox=\"0 0 500 500\" xmlns=\"http://www.w3.org/2000/svg\"><rect width=\"500\" height=\"500\" style=\"fill:#008000\" /><path d=\"M 0 0 L 0 50 L 450 500 L 500 500 L 0 0 \" stroke=\"#000000\" stroke-width=\"3\" fill=\"#000000\" /></svg>".to_string(),svg.create());
 
         match File::create("test.svg") {
             Ok(mut file) => {
@@ -364,4 +1150,197 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rect_shape() {
+        let mut rect = Rect::new(10.0,20.0,100.0,50.0);
+        rect.set_stroke_color(Color::Black);
+        rect.set_fill_color(Color::RGB(1,2,3));
+        rect.set_stroke_width(2.0);
+        assert_eq!("<rect x=\"10\" y=\"20\" width=\"100\" height=\"50\" stroke=\"#000000\" stroke-width=\"2\" fill=\"#010203\" />".to_string(),rect.create());
+    }
+
+    #[test]
+    fn test_circle_shape() {
+        let mut circle = Circle::new(50.0,50.0,25.0);
+        assert_eq!("<circle cx=\"50\" cy=\"50\" r=\"25\" stroke=\"none\" stroke-width=\"0\" fill=\"none\" />".to_string(),circle.create());
+    }
+
+    #[test]
+    fn test_ellipse_shape() {
+        let mut ellipse = Ellipse::new(50.0,50.0,25.0,10.0);
+        assert_eq!("<ellipse cx=\"50\" cy=\"50\" rx=\"25\" ry=\"10\" stroke=\"none\" stroke-width=\"0\" fill=\"none\" />".to_string(),ellipse.create());
+    }
+
+    #[test]
+    fn test_line_shape() {
+        let mut line = Line::new(0.0,0.0,100.0,100.0);
+        line.set_stroke_color(Color::Blue);
+        assert_eq!("<line x1=\"0\" y1=\"0\" x2=\"100\" y2=\"100\" stroke=\"#0000ff\" stroke-width=\"0\" fill=\"none\" />".to_string(),line.create());
+    }
+
+    #[test]
+    fn test_polyline_shape() {
+        let mut polyline = Polyline::new(vec![[0.0,0.0],[50.0,50.0],[100.0,0.0]]);
+        assert_eq!("<polyline points=\"0,0 50,50 100,0\" stroke=\"none\" stroke-width=\"0\" fill=\"none\" />".to_string(),polyline.create());
+    }
+
+    #[test]
+    fn test_polygon_shape() {
+        let mut polygon = Polygon::new(vec![[0.0,0.0],[50.0,50.0],[100.0,0.0]]);
+        assert_eq!("<polygon points=\"0,0 50,50 100,0\" stroke=\"none\" stroke-width=\"0\" fill=\"none\" />".to_string(),polygon.create());
+    }
+
+    #[test]
+    fn test_text_shape() {
+        let mut text = Text::new(10.0,20.0,"hello".to_string());
+        text.set_fill_color(Color::Black);
+        assert_eq!("<text x=\"10\" y=\"20\" stroke=\"none\" stroke-width=\"0\" fill=\"#000000\">hello</text>".to_string(),text.create());
+    }
+
+    #[test]
+    fn test_text_shape_escapes_content() {
+        let mut text = Text::new(0.0,0.0,"Q&A <tag> \"quoted\"".to_string());
+        assert_eq!("<text x=\"0\" y=\"0\" stroke=\"none\" stroke-width=\"0\" fill=\"none\">Q&amp;A &lt;tag&gt; &quot;quoted&quot;</text>".to_string(),text.create());
+    }
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(Color::None, parse_color("none"));
+        assert_eq!(Color::Black, parse_color("black"));
+        assert_eq!(Color::White, parse_color("white"));
+        assert_eq!(Color::Yellow, parse_color("yellow"));
+        assert_eq!(Color::Gray, parse_color("gray"));
+        assert_eq!(Color::Gray, parse_color("grey"));
+        assert_eq!(Color::Transparent, parse_color("transparent"));
+        assert_eq!(Color::RGB(10,100,50), parse_color("rgb(10,100,50)"));
+        assert_eq!(Color::RGB(255,0,128), parse_color("#ff0080"));
+        assert_eq!(Color::RGB(255,0,0), parse_color("#f00"));
+        assert_eq!(Color::None, parse_color("not-a-color"));
+    }
+
+    #[test]
+    fn test_parse_opacity() {
+        assert_eq!(Color::RGB(255,0,0), parse_opacity(Color::RGB(255,0,0), None));
+        assert_eq!(Color::RGB(255,0,0), parse_opacity(Color::RGB(255,0,0), Some("1")));
+        assert_eq!(Color::RGBA(255,0,0,128), parse_opacity(Color::RGB(255,0,0), Some("0.5019607843137255")));
+        assert_eq!(Color::None, parse_opacity(Color::None, Some("0.5")));
+    }
+
+    #[test]
+    fn test_color_to_svg_and_opacity() {
+        assert_eq!("none", Color::None.to_svg());
+        assert_eq!("#ffffff", Color::White.to_svg());
+        assert_eq!("#ffff00", Color::Yellow.to_svg());
+        assert_eq!("#808080", Color::Gray.to_svg());
+        assert_eq!("#000000", Color::Transparent.to_svg());
+        assert_eq!("#01ff80", Color::RGBA(1,255,128,64).to_svg());
+
+        assert_eq!(1.0, Color::Black.opacity());
+        assert_eq!(0.0, Color::Transparent.opacity());
+        assert_eq!(128.0 / 255.0, Color::RGBA(0,0,0,128).opacity());
+    }
+
+    #[test]
+    fn test_path_with_translucent_fill_emits_opacity() {
+        let mut path = Path::new();
+        path.set_fill_color(Color::RGBA(255,0,0,128));
+
+        assert_eq!("<path d=\"\" stroke=\"none\" stroke-width=\"0\" fill=\"#ff0000\" fill-opacity=\"0.5019607843137255\" />".to_string(),path.create());
+    }
+
+    #[test]
+    fn test_parse_round_trips_create() {
+        let mut svg = MinSVG::new([0.0,0.0,500.0,500.0]);
+
+        let mut path = Path::new();
+        path.set_stroke_color(Color::Black);
+        path.set_fill_color(Color::Black);
+        path.set_stroke_width(3.0);
+        path.move_to([0.0,0.0]);
+        path.line_to([0.0,50.0]);
+
+        svg.add_path(path);
+
+        let xml = svg.create();
+        let mut parsed = MinSVG::parse(&xml).unwrap();
+
+        assert_eq!(xml, parsed.create());
+    }
+
+    #[test]
+    fn test_parse_round_trips_translucent_fill() {
+        let mut svg = MinSVG::new([0.0,0.0,500.0,500.0]);
+
+        let mut path = Path::new();
+        path.set_fill_color(Color::RGBA(10,20,30,128));
+        path.move_to([0.0,0.0]);
+
+        svg.add_path(path);
+
+        let xml = svg.create();
+        let mut parsed = MinSVG::parse(&xml).unwrap();
+
+        assert_eq!(xml, parsed.create());
+        assert_eq!(Color::RGBA(10,20,30,128), parsed.paths[0].fill);
+    }
+
+    #[test]
+    fn test_parse_handles_scientific_notation_coordinates() {
+        let xml = "<svg viewBox=\"0 0 10 10\" xmlns=\"http://www.w3.org/2000/svg\"><path d=\"M 1e-5 2e-5 L 100 100\" stroke=\"none\" stroke-width=\"0\" fill=\"none\" /></svg>";
+        let mut parsed = MinSVG::parse(xml).unwrap();
+
+        assert_eq!("M 1e-5 2e-5 L 100 100 ".to_string(),parsed.paths[0].create_raw());
+    }
+
+    #[test]
+    fn test_parse_rejects_non_svg_root() {
+        assert!(MinSVG::parse("<notsvg viewBox=\"0 0 1 1\" />").is_err());
+    }
+
+    #[test]
+    fn test_add_shape_to_svg() {
+        let mut svg = MinSVG::new([0.0,0.0,100.0,100.0]);
+        svg.add_shape(Shape::Circle(Circle::new(50.0,50.0,25.0)));
+        assert_eq!("<svg viewBox=\"0 0 100 100\" xmlns=\"http://www.w3.org/2000/svg\"><circle cx=\"50\" cy=\"50\" r=\"25\" stroke=\"none\" stroke-width=\"0\" fill=\"none\" /></svg>".to_string(),svg.create());
+    }
+
+    #[test]
+    fn test_write_to_matches_create() {
+        let mut svg = MinSVG::new([0.0,0.0,100.0,100.0]);
+        svg.add_shape(Shape::Circle(Circle::new(50.0,50.0,25.0)));
+
+        let expected = svg.create();
+
+        let mut bytes = Vec::new();
+        svg.write_to(&mut bytes).unwrap();
+
+        assert_eq!(expected.as_bytes(), bytes.as_slice());
+    }
+
+    #[test]
+    fn test_to_bytes_matches_create() {
+        let mut svg = MinSVG::new([0.0,0.0,100.0,100.0]);
+        svg.add_shape(Shape::Circle(Circle::new(50.0,50.0,25.0)));
+
+        let expected = svg.create();
+
+        assert_eq!(expected.into_bytes(), svg.to_bytes());
+    }
+
+    #[test]
+    fn test_to_file_writes_svg() {
+        let mut svg = MinSVG::new([0.0,0.0,100.0,100.0]);
+        svg.add_shape(Shape::Circle(Circle::new(50.0,50.0,25.0)));
+
+        let expected = svg.create();
+
+        let path = std::env::temp_dir().join("svg_minimal_test_to_file.svg");
+        svg.to_file(&path).unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(expected, written);
+    }
+
  }